@@ -1,12 +1,14 @@
 extern crate env_logger;
 extern crate log;
 
-use std::{env, io};
+use std::env;
 use std::io::Write;
 use std::process::Command;
 
+use dialoguer::{theme::ColorfulTheme, Confirm, Input, Select};
 use log::{error, info, warn};
 use regex::Regex;
+use serde_json::json;
 
 /// Print an introductory message describing the program.
 fn print_intro() {
@@ -19,13 +21,108 @@ fn print_intro() {
     println!("\nLet's get started!");
 }
 
+/// Runtime options parsed from the command line.
+struct Config {
+    /// When set, print commands instead of running them (`--dry-run`/`--noop`).
+    /// Read-only `--version` probes for installed CLIs still run.
+    dry_run: bool,
+}
+
+impl Config {
+    /// Parse `Config` from the process's CLI arguments.
+    fn from_args() -> Self {
+        let dry_run = env::args().any(|arg| arg == "--dry-run" || arg == "--noop");
+        Config { dry_run }
+    }
+}
+
+/// Check the crate's GitHub releases for a newer version than the one
+/// currently running, and print a one-line notice if one is found.
+///
+/// Any request failure (offline, rate-limited, ...) is silently ignored, and
+/// the check itself can be skipped with `--no-update-check` or
+/// `QUACK_NO_UPDATE_CHECK`.
+fn check_for_update() {
+    let args_say_skip = env::args().any(|arg| arg == "--no-update-check");
+    if args_say_skip || env::var("QUACK_NO_UPDATE_CHECK").is_ok() {
+        return;
+    }
+
+    let client = match reqwest::blocking::Client::builder()
+        .user_agent("quack")
+        .timeout(std::time::Duration::from_secs(3))
+        .build()
+    {
+        Ok(client) => client,
+        Err(_) => return,
+    };
+
+    let response = match client
+        .get("https://api.github.com/repos/cameronspears/quack-gh/releases/latest")
+        .send()
+    {
+        Ok(response) => response,
+        Err(_) => return,
+    };
+
+    let body: serde_json::Value = match response.json() {
+        Ok(body) => body,
+        Err(_) => return,
+    };
+
+    let latest_tag = match body.get("tag_name").and_then(|v| v.as_str()) {
+        Some(tag) => tag,
+        None => return,
+    };
+    let latest_version = latest_tag.trim_start_matches('v');
+    let current_version = env!("CARGO_PKG_VERSION");
+
+    if is_newer_version(latest_version, current_version) {
+        let release_url = body
+            .get("html_url")
+            .and_then(|v| v.as_str())
+            .unwrap_or("https://github.com/cameronspears/quack-gh/releases/latest");
+        info!("A newer version of quack is available: {} -> {} ({})", current_version, latest_version, release_url);
+    }
+}
+
+/// Compare two `major.minor.patch`-style version strings and report whether
+/// `candidate` is strictly newer than `current`. Unparseable components are
+/// treated as `0`; if `candidate` has no numeric component at all, falls back
+/// to `false` rather than risk a misleading "newer version" notice.
+fn is_newer_version(candidate: &str, current: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> { v.split('.').map(|part| part.parse().unwrap_or(0)).collect() };
+    let candidate_parts = parse(candidate);
+    let current_parts = parse(current);
+
+    if candidate_parts.iter().all(|&n| n == 0) && candidate != "0" {
+        return false;
+    }
+
+    for i in 0..candidate_parts.len().max(current_parts.len()) {
+        let c = candidate_parts.get(i).copied().unwrap_or(0);
+        let r = current_parts.get(i).copied().unwrap_or(0);
+        if c != r {
+            return c > r;
+        }
+    }
+    false
+}
 
 /// Run shell command and capture the output.
-fn run_command(command: &str, args: &[&str]) -> Result<String, String> {
+///
+/// In dry-run mode, prints the command that would be executed and returns a
+/// synthetic success string instead of spawning the process.
+fn run_command(config: &Config, command: &str, args: &[&str]) -> Result<String, String> {
+    if config.dry_run {
+        println!("[dry-run] {} {}", command, args.join(" "));
+        return Ok(String::new());
+    }
+
     let output = Command::new(command)
         .args(args)
         .output()
-        .expect("Failed to execute command");
+        .map_err(|e| format!("Failed to execute '{}': {}", command, e))?;
 
     if output.status.success() {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -35,7 +132,7 @@ fn run_command(command: &str, args: &[&str]) -> Result<String, String> {
 }
 
 /// Ensures or installs Github CLI.
-fn ensure_gh_installed() -> Result<(), String> {
+fn ensure_gh_installed(config: &Config) -> Result<(), String> {
     match Command::new("gh").arg("--version").output() {
         Ok(_) => {
             info!("✅  GitHub CLI is already installed.");
@@ -43,13 +140,19 @@ fn ensure_gh_installed() -> Result<(), String> {
         }
         Err(_) => {
             info!("The GitHub CLI is required for authentication, repository creation, and other GitHub operations.");
-            print!("Do you want to install it? (y/n): ");
-            io::stdout().flush().unwrap();
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).expect("Failed to read line");
-            let input = input.trim().to_lowercase();
 
-            if input == "y" || input == "yes" {
+            if config.dry_run {
+                println!("[dry-run] would prompt to install the GitHub CLI");
+                return Ok(());
+            }
+
+            let should_install = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt("Do you want to install it?")
+                .default(true)
+                .interact()
+                .unwrap_or(false);
+
+            if should_install {
                 let os = env::consts::OS;
                 match os {
                     "macos" => {
@@ -70,6 +173,7 @@ fn ensure_gh_installed() -> Result<(), String> {
                             Err("Failed to install GitHub CLI using winget.".to_string())
                         }
                     }
+                    "linux" => install_gh_linux(),
                     _ => Err("Unsupported operating system.".to_string())
                 }
             } else {
@@ -79,10 +183,55 @@ fn ensure_gh_installed() -> Result<(), String> {
     }
 }
 
+/// Package managers `install_gh_linux` knows how to drive, in detection order,
+/// paired with the install command for `gh`.
+const LINUX_PACKAGE_MANAGERS: &[(&str, &[&str])] = &[
+    ("apt-get", &["apt-get", "install", "-y", "gh"]),
+    ("apt", &["apt", "install", "-y", "gh"]),
+    ("dnf", &["dnf", "install", "-y", "gh"]),
+    ("pacman", &["pacman", "-S", "--noconfirm", "github-cli"]),
+    ("zypper", &["zypper", "--non-interactive", "install", "gh"]),
+    ("brew", &["brew", "install", "gh"]),
+];
+
+/// Install the GitHub CLI on Linux, auto-detecting the first available
+/// package manager (`apt`/`apt-get`, `dnf`, `pacman`, `zypper`, falling back
+/// to `brew`). Falls back to printing the manual-install instructions and
+/// returning an error if none of them are present.
+fn install_gh_linux() -> Result<(), String> {
+    let manager = LINUX_PACKAGE_MANAGERS
+        .iter()
+        .find(|(bin, _)| Command::new(bin).arg("--version").output().is_ok());
+
+    let (bin, install_args) = match manager {
+        Some(found) => found,
+        None => {
+            info!("No supported package manager (apt, dnf, pacman, zypper, brew) was found.");
+            info!("Please install the GitHub CLI manually: https://github.com/cli/cli/blob/trunk/docs/install_linux.md");
+            return Err("Please install the GitHub CLI manually and rerun the program.".to_string());
+        }
+    };
+
+    info!("Found '{}'! Installing GitHub CLI...", bin);
+    let needs_sudo = *bin != "brew";
+    let status = if needs_sudo {
+        Command::new("sudo").args(*install_args).status()
+    } else {
+        Command::new(install_args[0]).args(&install_args[1..]).status()
+    }
+    .map_err(|e| format!("Failed to launch '{}': {}", bin, e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("Failed to install GitHub CLI using '{}'.", bin))
+    }
+}
+
 
 /// Check if the user is authenticated with GitHub.
-fn check_gh_authenticated() -> Result<(), String> {
-    match run_command("gh", &["auth", "status"]) {
+fn check_gh_authenticated(config: &Config) -> Result<(), String> {
+    match run_command(config, "gh", &["auth", "status"]) {
         Ok(_) => {
             info!("✅  You are already authenticated with GitHub.");
             Ok(())
@@ -91,6 +240,11 @@ fn check_gh_authenticated() -> Result<(), String> {
             info!("You are not logged in to GitHub via 'gh' CLI.");
             info!("Attempting automated authentication with predefined choices.");
 
+            if config.dry_run {
+                println!("[dry-run] gh auth login -p https -w");
+                return Ok(());
+            }
+
             let status = Command::new("gh")
                 .args(&[
                     "auth",
@@ -125,82 +279,257 @@ fn is_valid_repo_name(name: &str) -> bool {
 
 /// Get user input for repository name and visibility.
 fn get_repo_details() -> (String, String) {
-    let mut repo_name = String::new();
-    let mut repo_visibility = String::new();
+    let repo_name: String = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("New repo name? (prefix with 'gh:' or 'gl:' to pick a host, default GitHub)")
+        .validate_with(|input: &String| -> Result<(), &str> {
+            let bare = input.strip_prefix("gh:").or_else(|| input.strip_prefix("gl:")).unwrap_or(input);
+            // Accept a bare repo name, or an `owner/name` pair (one `/`), each
+            // segment checked against the same character rules.
+            let segments: Vec<&str> = bare.splitn(2, '/').collect();
+            if !segments.is_empty() && segments.iter().all(|s| is_valid_repo_name(s)) {
+                Ok(())
+            } else {
+                Err("Only alphanumeric characters, '.', '-', '_', and a single '/' (for 'owner/name') are allowed.")
+            }
+        })
+        .interact_text()
+        .expect("Failed to read repo name");
+
+    let visibility_options = ["public", "private"];
+    let selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Make repo public or private?")
+        .default(0)
+        .items(&visibility_options)
+        .interact()
+        .expect("Failed to read repo visibility");
+
+    (repo_name, visibility_options[selection].to_string())
+}
 
-    loop {
-        print!("\nNew repo name?: ");
-        io::stdout().flush().unwrap();
-        io::stdin().read_line(&mut repo_name).expect("Failed to read line");
-        repo_name = repo_name.trim().to_string();
 
-        if is_valid_repo_name(&repo_name) {
-            break;
-        } else {
-            warn!("Invalid repository name. Only alphanumeric characters and '.', '-', '_' are allowed.");
-            repo_name.clear();
+/// Create a new GitHub repository.
+fn create_github_repo(config: &Config, repo_name: &str, repo_visibility: &str) -> Result<String, String> {
+    match run_command(config, "gh", &["repo", "create", repo_name, &format!("--{}", repo_visibility), "--confirm"]) {
+        Ok(output) => {
+            if config.dry_run {
+                return Ok(format!("[dry-run] https://github.com/<you>/{}.git", repo_name));
+            }
+
+            for line in output.lines() {
+                if line.contains("git@") || line.contains("https://") {
+                    return Ok(line.trim().to_string());
+                }
+            }
+            Err("Could not capture GitHub URL.".to_string())
         }
+        Err(err) => Err(format!("Could not create GitHub repository: {}", err))
     }
+}
 
-    loop {
-        print!("Make repo public? Y/n: ");
-        io::stdout().flush().unwrap();
-        io::stdin().read_line(&mut repo_visibility).expect("Failed to read line");
-        repo_visibility = repo_visibility.trim().to_string();
-
-        // Default to "public" if nothing or just "Y" is entered
-        if repo_visibility.is_empty() || repo_visibility.to_lowercase() == "y" {
-            repo_visibility = "public".to_string();
-            break;
+/// Look up the login of the user the given token authenticates as.
+fn fetch_authenticated_login(client: &reqwest::blocking::Client, token: &str) -> Result<String, String> {
+    let response = client
+        .get("https://api.github.com/user")
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "quack")
+        .send()
+        .map_err(|e| format!("Could not reach GitHub API: {}", e))?;
+
+    let body: serde_json::Value = response
+        .json()
+        .map_err(|e| format!("Could not parse GitHub API response: {}", e))?;
+
+    body.get("login")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Could not determine the authenticated GitHub user.".to_string())
+}
+
+/// Create a new GitHub repository via the REST API, for machines without `gh` installed.
+///
+/// Requires a personal access token with the `repo` scope. Returns the same
+/// shape as `create_github_repo`: a clone URL suitable for `git remote add`.
+fn create_repo_via_api(config: &Config, repo_name: &str, private: bool, token: &str) -> Result<String, String> {
+    if config.dry_run {
+        println!("[dry-run] POST https://api.github.com/(user|orgs)/repos {{\"name\":\"{}\",\"private\":{}}}", repo_name, private);
+        return Ok(format!("[dry-run] https://github.com/<you>/{}.git", repo_name));
+    }
+
+    let client = reqwest::blocking::Client::new();
+
+    // `/user/repos` always creates under the authenticated user and rejects a
+    // '/' in the name. An `owner/name` input only needs the org endpoint when
+    // `owner` is a distinct Organization; when it's the token's own account,
+    // `/user/repos` with the bare name is what actually works.
+    let (url, bare_name) = match repo_name.split_once('/') {
+        Some((owner, name)) => {
+            let login = fetch_authenticated_login(&client, token)?;
+            if owner == login {
+                ("https://api.github.com/user/repos".to_string(), name)
+            } else {
+                (format!("https://api.github.com/orgs/{}/repos", owner), name)
+            }
         }
-        // Accept "n" for private repositories
-        else if repo_visibility.to_lowercase() == "n" {
-            repo_visibility = "private".to_string();
-            break;
-        } else {
-            warn!("Invalid option. Type 'Y' for public or 'n' for private.");
-            repo_visibility.clear();
+        None => ("https://api.github.com/user/repos".to_string(), repo_name),
+    };
+
+    let response = client
+        .post(&url)
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "quack")
+        .json(&json!({ "name": bare_name, "private": private }))
+        .send()
+        .map_err(|e| format!("Could not reach GitHub API: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(match status.as_u16() {
+            401 => "GitHub API rejected the request: token invalid.".to_string(),
+            403 => "GitHub API rejected the request: insufficient scope or private repos not allowed.".to_string(),
+            422 => format!("GitHub API rejected the request: a repository named '{}' already exists.", repo_name),
+            code => format!("GitHub API request failed with status {}.", code),
+        });
+    }
+
+    let body: serde_json::Value = response
+        .json()
+        .map_err(|e| format!("Could not parse GitHub API response: {}", e))?;
+
+    body.get("clone_url")
+        .or_else(|| body.get("ssh_url"))
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| "Could not capture GitHub URL from API response.".to_string())
+}
+
+/// A forge quack can create repositories on (GitHub, GitLab, ...).
+///
+/// Each host owns its own CLI-presence check, authentication, and repo
+/// creation, so `main` can dispatch through a trait object instead of
+/// branching on the host everywhere.
+trait RepoHost {
+    /// Ensure the host's CLI is available. GitHub offers to install `gh` if
+    /// the user agrees; GitLab only reports a missing `glab`.
+    fn ensure_cli(&self, config: &Config) -> Result<(), String>;
+    /// Ensure the user is authenticated with the host.
+    fn authenticate(&self, config: &Config) -> Result<(), String>;
+    /// Create a new repository and return its clone URL.
+    fn create(&self, config: &Config, name: &str, visibility: &str) -> Result<String, String>;
+    /// The clone URL a repository of this name would have on this host,
+    /// without creating anything. Used for dry-run previews.
+    fn remote_url(&self, name: &str) -> String;
+}
+
+/// GitHub, via the `gh` CLI (falling back to the REST API with `GITHUB_TOKEN`).
+struct GitHub;
+
+impl RepoHost for GitHub {
+    fn ensure_cli(&self, config: &Config) -> Result<(), String> {
+        if Command::new("gh").arg("--version").output().is_ok() || env::var("GITHUB_TOKEN").is_ok() {
+            return Ok(());
         }
+        ensure_gh_installed(config)
     }
 
-    (repo_name, repo_visibility)
+    fn authenticate(&self, config: &Config) -> Result<(), String> {
+        if Command::new("gh").arg("--version").output().is_err() {
+            return Ok(()); // falling back to the API; token presence is checked at create() time
+        }
+        check_gh_authenticated(config)
+    }
+
+    fn create(&self, config: &Config, name: &str, visibility: &str) -> Result<String, String> {
+        if Command::new("gh").arg("--version").output().is_err() {
+            let token = env::var("GITHUB_TOKEN")
+                .map_err(|_| "Neither the GitHub CLI nor GITHUB_TOKEN is available.".to_string())?;
+            return create_repo_via_api(config, name, visibility == "private", &token);
+        }
+        create_github_repo(config, name, visibility)
+    }
+
+    fn remote_url(&self, name: &str) -> String {
+        format!("https://github.com/{}.git", name)
+    }
 }
 
+/// GitLab, via the `glab` CLI.
+struct GitLab;
 
-/// Create a new GitHub repository.
-fn create_github_repo(repo_name: &str, repo_visibility: &str) -> Result<String, String> {
-    match run_command("gh", &["repo", "create", repo_name, &format!("--{}", repo_visibility), "--confirm"]) {
-        Ok(output) => {
-            for line in output.lines() {
-                if line.contains("git@") || line.contains("https://") {
-                    return Ok(line.trim().to_string());
+impl RepoHost for GitLab {
+    fn ensure_cli(&self, _config: &Config) -> Result<(), String> {
+        if Command::new("glab").arg("--version").output().is_ok() {
+            Ok(())
+        } else {
+            Err("The 'glab' CLI is required for GitLab support; install it from https://gitlab.com/gitlab-org/cli and rerun.".to_string())
+        }
+    }
+
+    fn authenticate(&self, config: &Config) -> Result<(), String> {
+        match run_command(config, "glab", &["auth", "status"]) {
+            Ok(_) => Ok(()),
+            Err(_) => run_command(config, "glab", &["auth", "login"]).map(|_| ()),
+        }
+    }
+
+    fn create(&self, config: &Config, name: &str, visibility: &str) -> Result<String, String> {
+        if config.dry_run {
+            println!("[dry-run] glab repo create {} --{}", name, visibility);
+            return Ok(self.remote_url(name));
+        }
+
+        match run_command(config, "glab", &["repo", "create", name, &format!("--{}", visibility)]) {
+            Ok(output) => {
+                for line in output.lines() {
+                    if line.contains("git@") || line.contains("https://") {
+                        return Ok(line.trim().to_string());
+                    }
                 }
+                Err("Could not capture GitLab URL.".to_string())
             }
-            Err("Could not capture GitHub URL.".to_string())
+            Err(err) => Err(format!("Could not create GitLab repository: {}", err)),
         }
-        Err(err) => Err(format!("Could not create GitHub repository: {}", err))
+    }
+
+    fn remote_url(&self, name: &str) -> String {
+        format!("https://gitlab.com/{}.git", name)
+    }
+}
+
+/// Resolve a `gh:owner/name` / `gl:owner/name` scheme prefix (default: GitHub)
+/// to a host implementation and the bare repo name.
+fn resolve_host(repo_name: &str) -> (Box<dyn RepoHost>, String) {
+    if let Some(rest) = repo_name.strip_prefix("gl:") {
+        (Box::new(GitLab), rest.to_string())
+    } else if let Some(rest) = repo_name.strip_prefix("gh:") {
+        (Box::new(GitHub), rest.to_string())
+    } else {
+        (Box::new(GitHub), repo_name.to_string())
     }
 }
 
 /// Initialize git and set remote URL.
-fn handle_git_remote(new_github_url: &str) -> Result<String, String> {
+fn handle_git_remote(config: &Config, new_github_url: &str) -> Result<String, String> {
     // Prompt user about setting git remotes
-    print!("Link local repo with new repo? (Y/n): ");
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).expect("Failed to read line");
-    let input = input.trim().to_lowercase();
+    let should_link = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Link local repo with new repo?")
+        .default(true)
+        .interact()
+        .unwrap_or(false);
+
+    if should_link {
+        if config.dry_run {
+            info!("[dry-run] skipping 'git remote add' and other repo mutations.");
+            return Ok("Skipped".to_string());
+        }
 
-    // Default to "yes" if input is empty
-    if input.is_empty() || input == "y" || input == "yes" {
-        run_command("git", &["init"])?;
+        run_command(config, "git", &["init"])?;
 
-        match run_command("git", &["remote"]) {
+        match run_command(config, "git", &["remote"]) {
             Ok(output) => {
                 if output.contains("origin") {
-                    run_command("git", &["remote", "set-url", "origin", new_github_url])
+                    run_command(config, "git", &["remote", "set-url", "origin", new_github_url])
                 } else {
-                    run_command("git", &["remote", "add", "origin", new_github_url])
+                    run_command(config, "git", &["remote", "add", "origin", new_github_url])
                 }
             }
             Err(err) => Err(format!("Could not set git remote: {}", err))
@@ -211,51 +540,312 @@ fn handle_git_remote(new_github_url: &str) -> Result<String, String> {
     }
 }
 
-fn main() {
-    // Print the introductory message
-    print_intro();
-
-    // Initialize the logger
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
-        .format(|buf, record| {
-            writeln!(buf, "{}", record.args())
-        })
-        .init();
+/// Run the full "create a repo from the current directory" flow.
+fn run_create_flow(config: &Config) {
+    let (repo_name, repo_visibility) = get_repo_details();
+    let (host, repo_name) = resolve_host(&repo_name);
 
-    // Ensure GitHub CLI is installed
-    if let Err(err) = ensure_gh_installed() {
-        error!("GitHub CLI Error: {}", err);
-        std::process::exit(1);
+    if let Err(err) = host.ensure_cli(config) {
+        error!("CLI Error: {}", err);
+        return;
     }
 
-    // Authentication
-    if let Err(err) = check_gh_authenticated() {
+    if let Err(err) = host.authenticate(config) {
         error!("Authentication Error: {}", err);
-        std::process::exit(1);
+        return;
     }
 
-    let (repo_name, repo_visibility) = get_repo_details();
+    let repo_creation_result = host.create(config, &repo_name, &repo_visibility);
 
-    match create_github_repo(&repo_name, &repo_visibility) {
+    match repo_creation_result {
         Ok(github_url) => {
-            let git_remote_result = handle_git_remote(&github_url);
-            match git_remote_result {
+            match handle_git_remote(config, &github_url) {
                 Ok(msg) => {
                     if msg == "Skipped" {
                         info!("GitHub repository created.");
                     } else {
-                        info!("GitHub repository created and linked. You can now manually add, commit, and push files.");
+                        info!("GitHub repository created and linked.");
+                        if let Err(err) = create_license_and_readme(config, &repo_name) {
+                            error!("Could not create LICENSE/README/.gitignore: {}", err);
+                        }
+                        if let Err(err) = initial_commit_and_push(config) {
+                            error!("Error: {}", err);
+                        }
                     }
                 }
-                Err(err) => {
-                    error!("Error: {}", err);
-                    std::process::exit(1);
-                }
+                Err(err) => error!("Error: {}", err),
+            }
+        }
+        Err(err) => error!("Error: {}", err),
+    }
+}
+
+/// SPDX identifiers offered in the license picker, matching GitHub's own
+/// "Add a license" quick picks.
+const LICENSE_CHOICES: &[&str] = &["MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "MPL-2.0", "Unlicense"];
+
+/// Fetch a path off `api.github.com` as JSON, via `gh api` when the CLI is
+/// present and a plain (optionally authenticated) HTTP GET otherwise — so
+/// this works in the GitLab and gh-less `GITHUB_TOKEN` flows too, not just
+/// the `gh`-installed GitHub path.
+fn fetch_github_api_json(config: &Config, path: &str, token: Option<&str>) -> Result<serde_json::Value, String> {
+    let raw = if Command::new("gh").arg("--version").output().is_ok() {
+        run_command(config, "gh", &["api", path])?
+    } else {
+        let client = reqwest::blocking::Client::new();
+        let mut request = client
+            .get(format!("https://api.github.com{}", path))
+            .header("User-Agent", "quack");
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        request
+            .send()
+            .map_err(|e| format!("Could not reach GitHub API: {}", e))?
+            .text()
+            .map_err(|e| format!("Could not read GitHub API response: {}", e))?
+    };
+
+    serde_json::from_str(&raw).map_err(|e| format!("Could not parse GitHub API response for '{}': {}", path, e))
+}
+
+/// Let the user pick a license, fetch it from GitHub's license API, and write
+/// a real `LICENSE` file plus a `.gitignore` chosen from GitHub's templates.
+fn create_license_and_readme(config: &Config, repo_name: &str) -> Result<(), String> {
+    let mut readme = std::fs::File::create("README.md").map_err(|e| e.to_string())?;
+    writeln!(readme, "# {}", repo_name).map_err(|e| e.to_string())?;
+
+    let license_selection = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt("Choose a license")
+        .items(LICENSE_CHOICES)
+        .default(0)
+        .interact()
+        .map_err(|e| e.to_string())?;
+    let license_key = LICENSE_CHOICES[license_selection];
+
+    if config.dry_run {
+        info!("[dry-run] would write LICENSE ({}) and .gitignore", license_key);
+        return Ok(());
+    }
+
+    let token = env::var("GITHUB_TOKEN").ok();
+
+    let license = fetch_github_api_json(config, &format!("/licenses/{}", license_key), token.as_deref())?;
+    let body = license
+        .get("body")
+        .and_then(|v| v.as_str())
+        .ok_or("License response had no body".to_string())?;
+
+    let fullname = match fetch_github_api_json(config, "/user", token.as_deref()) {
+        Ok(user) => user
+            .get("name")
+            .and_then(|v| v.as_str())
+            .filter(|n| !n.is_empty())
+            .or_else(|| user.get("login").and_then(|v| v.as_str()))
+            .unwrap_or("Unknown")
+            .to_string(),
+        Err(_) => "Unknown".to_string(),
+    };
+
+    let year = run_command(config, "date", &["+%Y"]).unwrap_or_default();
+    let license_text = body
+        .replace("[year]", &year)
+        .replace("[fullname]", &fullname);
+
+    std::fs::write("LICENSE", license_text).map_err(|e| e.to_string())?;
+
+    let gitignore_key = Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Which .gitignore template? (e.g. Rust, Node)")
+        .default("Rust".to_string())
+        .interact_text()
+        .map_err(|e| e.to_string())?;
+
+    let mut wrote_gitignore = false;
+    match fetch_github_api_json(config, &format!("/gitignore/templates/{}", gitignore_key), token.as_deref()) {
+        Ok(gitignore) => {
+            if let Some(source) = gitignore.get("source").and_then(|v| v.as_str()) {
+                std::fs::write(".gitignore", source).map_err(|e| e.to_string())?;
+                wrote_gitignore = true;
+            }
+        }
+        Err(err) => warn!("Could not fetch .gitignore template '{}': {}", gitignore_key, err),
+    }
+
+    if wrote_gitignore {
+        info!("Wrote LICENSE ({}), README.md, and .gitignore.", license_key);
+    } else {
+        info!("Wrote LICENSE ({}) and README.md.", license_key);
+    }
+    Ok(())
+}
+
+/// Stage, commit, and push everything in the current directory to `main`.
+///
+/// Skips cleanly if there's nothing to commit. Offers the user a chance to
+/// decline, since this is the last step that actually pushes to the remote.
+fn initial_commit_and_push(config: &Config) -> Result<(), String> {
+    let should_push = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Stage, commit, and push everything to 'main' now?")
+        .default(true)
+        .interact()
+        .unwrap_or(false);
+
+    if !should_push {
+        info!("Skipped initial commit and push.");
+        return Ok(());
+    }
+
+    run_command(config, "git", &["add", "-A"])?;
+
+    if !config.dry_run {
+        let status = run_command(config, "git", &["status", "--porcelain"])?;
+        if status.is_empty() {
+            info!("Nothing to commit.");
+            return Ok(());
+        }
+    }
+
+    run_command(config, "git", &["commit", "-m", "first commit"])?;
+    run_command(config, "git", &["branch", "-M", "main"])?;
+    run_command(config, "git", &["push", "-u", "origin", "main"])?;
+
+    info!("Pushed initial commit to 'main'.");
+    Ok(())
+}
+
+/// Fetch the current user's repository names via `gh repo list`.
+fn list_repo_names(config: &Config) -> Result<Vec<String>, String> {
+    let output = run_command(config, "gh", &["repo", "list", "--limit", "100"])?;
+    Ok(output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next().map(str::to_string))
+        .collect())
+}
+
+/// Prompt the user to pick one repository name from a list, or `None` if they cancel.
+fn select_repo(repos: &[String], prompt: &str) -> Option<String> {
+    Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .items(repos)
+        .interact_opt()
+        .unwrap_or(None)
+        .map(|i| repos[i].clone())
+}
+
+/// List the current user's repositories and offer per-repo actions.
+fn list_repositories(config: &Config) {
+    let repos = match list_repo_names(config) {
+        Ok(repos) => repos,
+        Err(err) => {
+            error!("Could not list repositories: {}", err);
+            return;
+        }
+    };
+    if repos.is_empty() {
+        info!("No repositories found.");
+        return;
+    }
+
+    let repo = match select_repo(&repos, "Select a repository") {
+        Some(repo) => repo,
+        None => return,
+    };
+
+    let actions = ["Visit in browser", "Delete", "Cancel"];
+    let action = Select::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("What would you like to do with '{}'?", repo))
+        .items(&actions)
+        .default(0)
+        .interact()
+        .unwrap_or(2);
+
+    match action {
+        0 => {
+            if let Err(err) = run_command(config, "gh", &["repo", "view", &repo, "--web"]) {
+                error!("Could not open repository in browser: {}", err);
             }
         }
+        1 => delete_repository(config, &repo),
+        _ => info!("Cancelled."),
+    }
+}
+
+/// Confirm and delete a single repository by name.
+fn delete_repository(config: &Config, repo: &str) {
+    let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!("Really delete '{}'? This cannot be undone.", repo))
+        .default(false)
+        .interact()
+        .unwrap_or(false);
+
+    if !confirmed {
+        info!("Cancelled.");
+        return;
+    }
+
+    match run_command(config, "gh", &["repo", "delete", repo, "--yes"]) {
+        Ok(_) => info!("Deleted '{}'.", repo),
+        Err(err) => error!("Could not delete repository: {}", err),
+    }
+}
+
+/// Prompt the user to pick a repository to delete, then delete it.
+fn run_delete_flow(config: &Config) {
+    let repos = match list_repo_names(config) {
+        Ok(repos) => repos,
         Err(err) => {
-            error!("Error: {}", err);
-            std::process::exit(1);
+            error!("Could not list repositories: {}", err);
+            return;
+        }
+    };
+    if repos.is_empty() {
+        info!("No repositories found.");
+        return;
+    }
+
+    if let Some(repo) = select_repo(&repos, "Select a repository to delete") {
+        delete_repository(config, &repo);
+    }
+}
+
+fn main() {
+    // Print the introductory message
+    print_intro();
+
+    // Initialize the logger
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
+        .format(|buf, record| {
+            writeln!(buf, "{}", record.args())
+        })
+        .init();
+
+    let config = Config::from_args();
+    if config.dry_run {
+        info!("Running in dry-run mode: commands will be printed instead of executed.");
+    }
+
+    check_for_update();
+
+    let menu_options = [
+        "Create repository from current directory",
+        "List my repositories",
+        "Delete a repository",
+        "Exit",
+    ];
+
+    loop {
+        let choice = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("What would you like to do?")
+            .default(0)
+            .items(&menu_options)
+            .interact()
+            .expect("Failed to read menu selection");
+
+        match choice {
+            0 => run_create_flow(&config),
+            1 => list_repositories(&config),
+            2 => run_delete_flow(&config),
+            _ => break,
         }
     }
 }